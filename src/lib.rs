@@ -1,7 +1,15 @@
 //! This crate can be used to parse URIs like the ones we can found in OpenApi spec for paths (/foo/{bar}).
 //! Once the pattern is parsed, you can check if any string matches against it. You can also compare two patterns to find the more specific.
 //!
-//! For now it doesn't handle any other pattern than {pattern}. Feel free to open an issue if you have a need for a specific usecase.
+//! Beyond the basic `{placeholder}`, patterns support a few extra constructs:
+//! - capturing the value bound to each placeholder with [`UriPattern::captures`], and rewriting a
+//!   matched candidate through a replacement template with [`UriPattern::rewrite`];
+//! - constrained placeholders such as `{id:int}`, `{id:uuid}` or `{slug:[a-z0-9-]+}`, where the
+//!   segment must satisfy the constraint before the placeholder matches;
+//! - a greedy multi-segment placeholder `{**rest}` that matches the rest of the path;
+//! - resolving one candidate against many patterns at once with the trie-based [`UriRouter`].
+//!
+//! Feel free to open an issue if you have a need for a specific usecase.
 //! Can probably be used for paths on filesystems as well if one can find a usecase for this.
 //!
 //! # Example
@@ -40,10 +48,33 @@
 //! ```
 mod pattern_part;
 mod uri_pattern_score;
+mod uri_router;
 
 use crate::pattern_part::PatternPart;
 use crate::uri_pattern_score::UriPatternScore;
+pub use crate::uri_router::UriRouter;
 use core::cmp::Ordering;
+use core::fmt;
+use std::collections::HashMap;
+
+/// Error returned by [`UriPattern::parse`] when a pattern string is malformed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UriPatternError {
+    /// A pattern carried more than one `{**name}` multi-joker placeholder.
+    MultipleMultiJokers,
+}
+
+impl fmt::Display for UriPatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UriPatternError::MultipleMultiJokers => {
+                f.write_str("a pattern may contain at most one multi-joker placeholder")
+            }
+        }
+    }
+}
+
+impl std::error::Error for UriPatternError {}
 
 /// Struct used to parse strings as patterns - Check if an incoming string matches a pattern - Pattern Comparison
 #[derive(Debug, Clone)]
@@ -62,6 +93,34 @@ impl<'a> From<&'a str> for UriPattern<'a> {
     }
 }
 
+impl<'a> UriPattern<'a> {
+    /// Parses a pattern, rejecting strings that break the pattern grammar. A
+    /// pattern may contain at most one `{**name}` multi-joker; anything else is
+    /// accepted, so this only fails where [`From`] would otherwise build an
+    /// unusable pattern.
+    /// # Example
+    ///
+    /// ```rust
+    /// use uri_pattern_matcher::UriPattern;
+    ///
+    /// assert!(UriPattern::parse("/static/{**path}").is_ok());
+    /// assert!(UriPattern::parse("/static/{**a}/{**b}").is_err());
+    /// ```
+    pub fn parse(pattern: &'a str) -> Result<Self, UriPatternError> {
+        let pattern: UriPattern = pattern.into();
+        if pattern
+            .parts
+            .iter()
+            .filter(|part| matches!(part, PatternPart::MultiJoker(_)))
+            .count()
+            > 1
+        {
+            return Err(UriPatternError::MultipleMultiJokers);
+        }
+        Ok(pattern)
+    }
+}
+
 impl UriPattern<'_> {
     /// Method used to check if a candidate string matches against the pattern
     /// # Example
@@ -74,20 +133,128 @@ impl UriPattern<'_> {
     /// assert!(pattern.is_match("/api/customer/John/details"));
     /// ```
     pub fn is_match(&self, candidate: &str) -> bool {
-        let splitted = candidate.split('/').collect::<Vec<_>>();
-        if splitted.len() != self.parts.len() {
-            return false;
+        self.captures(candidate).is_some()
+    }
+
+    /// Method used to match a candidate string and capture the value bound to
+    /// each `{name}` placeholder. Returns `None` when the candidate doesn't
+    /// match (too few or too many segments, a literal mismatches or a
+    /// constraint is violated), otherwise a map from each placeholder name to
+    /// the substring it matched.
+    /// # Example
+    ///
+    /// ```rust
+    /// use uri_pattern_matcher::UriPattern;
+    ///
+    /// let pattern: UriPattern = "/api/{resource}/{id}/details".into();
+    /// let captures = pattern.captures("/api/customer/John/details").unwrap();
+    /// assert_eq!(captures.get("resource"), Some(&"customer"));
+    /// assert_eq!(captures.get("id"), Some(&"John"));
+    /// ```
+    pub fn captures<'b>(&'b self, candidate: &'b str) -> Option<HashMap<&'b str, &'b str>> {
+        let segments = candidate.split('/').collect::<Vec<_>>();
+        let mut captures = HashMap::new();
+        match self
+            .parts
+            .iter()
+            .position(|part| matches!(part, PatternPart::MultiJoker(_)))
+        {
+            // No multi-joker: the arity must match exactly and every segment is
+            // compared one-to-one.
+            None => {
+                if segments.len() != self.parts.len() {
+                    return None;
+                }
+                Self::match_segments(&self.parts, &segments, &mut captures)?;
+            }
+            // A multi-joker splits the walk in two: fixed parts are matched from
+            // the front, the remaining fixed parts from the back, and everything
+            // in between is bound to the multi-joker (one or more segments).
+            Some(index) => {
+                let front = &self.parts[..index];
+                let back = &self.parts[index + 1..];
+                if segments.len() < front.len() + back.len() + 1 {
+                    return None;
+                }
+                let back_start = segments.len() - back.len();
+                Self::match_segments(front, &segments[..front.len()], &mut captures)?;
+                Self::match_segments(back, &segments[back_start..], &mut captures)?;
+                if let PatternPart::MultiJoker(name) = self.parts[index] {
+                    let start = segments[..front.len()]
+                        .iter()
+                        .map(|segment| segment.len() + 1)
+                        .sum::<usize>();
+                    let len = segments[front.len()..back_start]
+                        .iter()
+                        .map(|segment| segment.len() + 1)
+                        .sum::<usize>()
+                        - 1;
+                    captures.insert(name, &candidate[start..start + len]);
+                }
+            }
+        }
+        Some(captures)
+    }
+
+    /// Matches `parts` against `segments` of the same length one-to-one,
+    /// recording placeholder captures. Returns `None` on the first mismatch or
+    /// violated constraint.
+    fn match_segments<'b>(
+        parts: &[PatternPart<'b>],
+        segments: &[&'b str],
+        captures: &mut HashMap<&'b str, &'b str>,
+    ) -> Option<()> {
+        for (part, value) in parts.iter().zip(segments) {
+            match part {
+                PatternPart::Value(s) => {
+                    if s != value {
+                        return None;
+                    }
+                }
+                PatternPart::Joker(name, constraint) => {
+                    if let Some(constraint) = constraint {
+                        if !constraint.matches(value) {
+                            return None;
+                        }
+                    }
+                    captures.insert(*name, *value);
+                }
+                // The single multi-joker is handled by the caller and never
+                // reaches the fixed front/back slices.
+                PatternPart::MultiJoker(_) => return None,
+            }
         }
-        !splitted
-            .into_iter()
-            .enumerate()
-            .map(|(key, value)| match self.parts.get(key) {
-                Some(PatternPart::Joker) => true,
-                Some(PatternPart::Value(s)) => *s == value,
-                None => false,
-            })
-            .collect::<Vec<bool>>()
-            .contains(&false)
+        Some(())
+    }
+
+    /// Method used to rewrite a matched candidate through a replacement
+    /// `template`. The template is itself parsed into [`PatternPart`]s: literal
+    /// parts are emitted verbatim and each `{name}` part is replaced by the
+    /// value captured for that name in `candidate`. Returns `None` when the
+    /// candidate doesn't match the pattern, the template is malformed, or the
+    /// template references a placeholder that the pattern never captured.
+    /// # Example
+    ///
+    /// ```rust
+    /// use uri_pattern_matcher::UriPattern;
+    ///
+    /// let pattern: UriPattern = "/api/{resource}/{id}/details".into();
+    /// let rewritten = pattern.rewrite("/api/posts/42/details", "/v2/{resource}/{id}");
+    /// assert_eq!(rewritten.as_deref(), Some("/v2/posts/42"));
+    /// ```
+    pub fn rewrite(&self, candidate: &str, template: &str) -> Option<String> {
+        let captures = self.captures(candidate)?;
+        let template = UriPattern::parse(template).ok()?;
+        let mut rendered = Vec::with_capacity(template.parts.len());
+        for part in &template.parts {
+            match part {
+                PatternPart::Value(s) => rendered.push((*s).to_string()),
+                PatternPart::Joker(name, _) | PatternPart::MultiJoker(name) => {
+                    rendered.push(captures.get(name)?.to_string())
+                }
+            }
+        }
+        Some(rendered.join("/"))
     }
 }
 
@@ -101,9 +268,7 @@ impl PartialEq for UriPattern<'_> {
 
 impl PartialOrd for UriPattern<'_> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let score: UriPatternScore = self.into();
-        let other_score: UriPatternScore = other.into();
-        score.partial_cmp(&other_score)
+        Some(self.cmp(other))
     }
 }
 
@@ -127,6 +292,142 @@ mod tests {
         assert!(pattern.is_match("/a/resource/test/d"));
     }
 
+    #[test]
+    fn captures_bind_placeholder_values() {
+        let pattern: UriPattern = "/api/{resource}/{id}/details".into();
+        let captures = pattern.captures("/api/customer/John/details").unwrap();
+        assert_eq!(captures.get("resource"), Some(&"customer"));
+        assert_eq!(captures.get("id"), Some(&"John"));
+        assert!(pattern.captures("/api/customer/John").is_none());
+        assert!(pattern.captures("/api/customer/John/summary").is_none());
+    }
+
+    #[test]
+    fn rewrite_substitutes_captured_values() {
+        let pattern: UriPattern = "/api/{resource}/{id}/details".into();
+        assert_eq!(
+            pattern.rewrite("/api/posts/42/details", "/v2/{resource}/{id}"),
+            Some("/v2/posts/42".to_string())
+        );
+        assert!(pattern.rewrite("/api/posts/42", "/v2/{resource}/{id}").is_none());
+        assert!(pattern
+            .rewrite("/api/posts/42/details", "/v2/{unknown}")
+            .is_none());
+    }
+
+    #[test]
+    fn constraints_restrict_matching_segments() {
+        let pattern: UriPattern = "/api/{resource}/{id:int}".into();
+        assert!(pattern.is_match("/api/posts/42"));
+        assert!(!pattern.is_match("/api/posts/abc"));
+
+        let slug: UriPattern = "/blog/{slug:[a-z0-9-]+}".into();
+        assert!(slug.is_match("/blog/hello-world-1"));
+        assert!(!slug.is_match("/blog/Hello_World"));
+
+        let uuid: UriPattern = "/user/{id:uuid}".into();
+        assert!(uuid.is_match("/user/936da01f-9abd-4d9d-80c7-02af85c822a8"));
+        assert!(!uuid.is_match("/user/not-a-uuid"));
+    }
+
+    #[test]
+    fn constrained_joker_is_more_specific_than_bare_joker() {
+        let constrained: UriPattern = "/api/{id:int}".into();
+        let bare: UriPattern = "/api/{id}".into();
+        let literal: UriPattern = "/api/users".into();
+        assert!(constrained > bare);
+        assert!(literal > constrained);
+    }
+
+    #[test]
+    fn multi_joker_matches_rest_of_path() {
+        let pattern: UriPattern = "/static/{**path}".into();
+        assert!(pattern.is_match("/static/css/app.min.css"));
+        assert!(pattern.is_match("/static/favicon.ico"));
+        // The multi-joker requires at least one segment.
+        assert!(!pattern.is_match("/static"));
+
+        let captures = pattern.captures("/static/css/app.min.css").unwrap();
+        assert_eq!(captures.get("path"), Some(&"css/app.min.css"));
+    }
+
+    #[test]
+    fn multi_joker_matches_front_and_back() {
+        let pattern: UriPattern = "/files/{**path}/raw".into();
+        let captures = pattern.captures("/files/a/b/c/raw").unwrap();
+        assert_eq!(captures.get("path"), Some(&"a/b/c"));
+        assert!(!pattern.is_match("/files/raw"));
+    }
+
+    #[test]
+    fn multi_joker_is_less_specific_than_single_joker() {
+        let multi: UriPattern = "/static/{**path}".into();
+        let single: UriPattern = "/static/{file}".into();
+        assert!(single > multi);
+    }
+
+    #[test]
+    fn router_resolves_most_specific_pattern() {
+        let patterns: Vec<UriPattern> = vec![
+            "/api/{resource}/{id}".into(),
+            "/api/users/me".into(),
+            "/static/{**path}".into(),
+        ];
+        let router: UriRouter = patterns.iter().collect();
+
+        let (matched, captures) = router.resolve("/api/users/me").unwrap();
+        assert_eq!(matched.value, "/api/users/me");
+        assert!(captures.is_empty());
+
+        let (matched, captures) = router.resolve("/api/posts/42").unwrap();
+        assert_eq!(matched.value, "/api/{resource}/{id}");
+        assert_eq!(captures.get("resource"), Some(&"posts"));
+
+        let (matched, captures) = router.resolve("/static/css/app.css").unwrap();
+        assert_eq!(matched.value, "/static/{**path}");
+        assert_eq!(captures.get("path"), Some(&"css/app.css"));
+
+        assert!(router.resolve("/unknown").is_none());
+    }
+
+    #[test]
+    fn router_resolve_agrees_with_max() {
+        let patterns: Vec<UriPattern> = vec!["/{x}/b".into(), "/a/{y}".into()];
+        let candidate = "/a/b";
+        let best = patterns
+            .iter()
+            .filter(|p| p.is_match(candidate))
+            .max()
+            .unwrap();
+        let router: UriRouter = patterns.iter().collect();
+        assert_eq!(router.resolve(candidate).unwrap().0.value, best.value);
+    }
+
+    #[test]
+    fn router_prefers_tighter_colliding_pattern() {
+        let patterns: Vec<UriPattern> = vec!["/api/{id}".into(), "/api/{id:int}".into()];
+        let router: UriRouter = patterns.iter().collect();
+        assert_eq!(router.resolve("/api/42").unwrap().0.value, "/api/{id:int}");
+        assert_eq!(router.resolve("/api/abc").unwrap().0.value, "/api/{id}");
+    }
+
+    #[test]
+    fn parse_rejects_multiple_multi_jokers() {
+        assert_eq!(
+            UriPattern::parse("/static/{**a}/{**b}"),
+            Err(UriPatternError::MultipleMultiJokers)
+        );
+        assert!(UriPattern::parse("/static/{**path}").is_ok());
+    }
+
+    #[test]
+    fn rewrite_returns_none_on_malformed_template() {
+        let pattern: UriPattern = "/static/{**path}".into();
+        assert!(pattern
+            .rewrite("/static/css/app.css", "/assets/{**a}/{**b}")
+            .is_none());
+    }
+
     #[test]
     fn non_equality_works() {
         let pattern: UriPattern = "/a/{b}/{c}/d".into();
@@ -0,0 +1,120 @@
+use regex::Regex;
+
+/// A single `/`-delimited part of a parsed [`crate::UriPattern`].
+///
+/// A part is a literal [`Value`](PatternPart::Value) that must be matched
+/// verbatim, a [`Joker`](PatternPart::Joker) placeholder (written `{name}` in
+/// the source pattern) that matches any single segment and binds it to `name`,
+/// or a [`MultiJoker`](PatternPart::MultiJoker) placeholder (written `{**name}`)
+/// that matches one or more segments.
+/// A placeholder may carry a [`Constraint`] (written `{name:constraint}`) that
+/// the matched segment has to satisfy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternPart<'a> {
+    /// A literal segment that must be matched exactly.
+    Value(&'a str),
+    /// A `{name}` placeholder that matches any single segment, carrying the
+    /// placeholder name and an optional [`Constraint`] parsed out of the braces.
+    Joker(&'a str, Option<Constraint>),
+    /// A `{**name}` placeholder that greedily matches one or more consecutive
+    /// segments, binding the joined segments to `name`.
+    MultiJoker(&'a str),
+}
+
+/// A constraint restricting the content a [`Joker`](PatternPart::Joker)
+/// placeholder is allowed to match.
+///
+/// Written after a colon inside the braces, e.g. `{id:int}` or
+/// `{slug:[a-z0-9-]+}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Constraint {
+    /// The segment must parse as an unsigned integer.
+    Int,
+    /// The segment must be a hyphenated UUID (`8-4-4-4-12` hex digits).
+    Uuid,
+    /// The segment must fully match the given regular expression.
+    Regex(RegexConstraint),
+}
+
+/// A regular-expression [`Constraint`], compiled once when the pattern is
+/// parsed so that matching never recompiles on the hot path.
+///
+/// The source is anchored (`\A(?:…)\z`) so the whole segment has to match. An
+/// invalid expression compiles to `None`, which makes the constraint reject
+/// every segment deterministically instead of failing on each comparison.
+#[derive(Debug, Clone)]
+pub struct RegexConstraint {
+    source: String,
+    matcher: Option<Regex>,
+}
+
+impl RegexConstraint {
+    fn new(source: &str) -> Self {
+        let matcher = Regex::new(&format!("\\A(?:{})\\z", source)).ok();
+        Self {
+            source: source.to_string(),
+            matcher,
+        }
+    }
+
+    fn matches(&self, value: &str) -> bool {
+        self.matcher.as_ref().is_some_and(|re| re.is_match(value))
+    }
+}
+
+impl PartialEq for RegexConstraint {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for RegexConstraint {}
+
+impl Constraint {
+    /// Returns `true` if `value` satisfies the constraint.
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            Constraint::Int => value.parse::<u64>().is_ok(),
+            Constraint::Uuid => is_uuid(value),
+            Constraint::Regex(regex) => regex.matches(value),
+        }
+    }
+}
+
+fn is_uuid(value: &str) -> bool {
+    let groups = [8, 4, 4, 4, 12];
+    let parts = value.split('-').collect::<Vec<_>>();
+    parts.len() == groups.len()
+        && parts
+            .iter()
+            .zip(groups)
+            .all(|(part, len)| part.len() == len && part.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+impl<'a> From<&'a str> for PatternPart<'a> {
+    fn from(part: &'a str) -> Self {
+        if part.starts_with('{') && part.ends_with('}') {
+            let inner = &part[1..part.len() - 1];
+            if let Some(name) = inner.strip_prefix("**") {
+                PatternPart::MultiJoker(name)
+            } else {
+                match inner.split_once(':') {
+                    Some((name, constraint)) => PatternPart::Joker(name, Some(constraint.into())),
+                    None => PatternPart::Joker(inner, None),
+                }
+            }
+        } else {
+            PatternPart::Value(part)
+        }
+    }
+}
+
+impl From<&str> for Constraint {
+    fn from(constraint: &str) -> Self {
+        match constraint {
+            "int" => Constraint::Int,
+            "uuid" => Constraint::Uuid,
+            other => Constraint::Regex(RegexConstraint::new(other)),
+        }
+    }
+}
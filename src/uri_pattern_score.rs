@@ -0,0 +1,29 @@
+use crate::pattern_part::PatternPart;
+use crate::UriPattern;
+
+/// Specificity score of a [`UriPattern`], used to compare two patterns.
+///
+/// Each part contributes a weight (a literal is more specific than a
+/// constrained joker, which is itself more specific than a bare joker).
+/// The weights are stored from the last part to the first, so that two patterns
+/// are compared starting from their trailing segments: a literal closer to the
+/// end of the path makes a pattern more specific.
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub(crate) struct UriPatternScore(Vec<usize>);
+
+impl From<&UriPattern<'_>> for UriPatternScore {
+    fn from(pattern: &UriPattern) -> Self {
+        let scores = pattern
+            .parts
+            .iter()
+            .rev()
+            .map(|part| match part {
+                PatternPart::Value(_) => 3,
+                PatternPart::Joker(_, Some(_)) => 2,
+                PatternPart::Joker(_, None) => 1,
+                PatternPart::MultiJoker(_) => 0,
+            })
+            .collect();
+        Self(scores)
+    }
+}
@@ -0,0 +1,131 @@
+use crate::pattern_part::PatternPart;
+use crate::UriPattern;
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+/// A segment-keyed prefix trie resolving one candidate against many
+/// [`UriPattern`]s.
+///
+/// The documented "best match" workflow
+/// (`patterns.iter().filter(is_match).max()`) re-walks every pattern for every
+/// lookup. A [`UriRouter`] instead indexes the patterns once so a lookup only
+/// descends the segments of the candidate, gathering every pattern that matches
+/// without scanning unrelated ones. To stay consistent with that workflow, the
+/// winner among the gathered candidates is chosen with the same
+/// [`UriPattern`] `Ord` specificity that `max()` uses, so `resolve` and `max()`
+/// always agree on the best match.
+///
+/// # Example
+///
+/// ```rust
+/// use uri_pattern_matcher::{UriPattern, UriRouter};
+///
+/// let patterns: Vec<UriPattern> = vec![
+///     "/api/{resource}/{id}".into(),
+///     "/api/users/me".into(),
+///     "/static/{**path}".into(),
+/// ];
+/// let router: UriRouter = patterns.iter().collect();
+///
+/// let (matched, captures) = router.resolve("/api/users/me").unwrap();
+/// assert_eq!(matched.value, "/api/users/me");
+/// assert!(captures.is_empty());
+///
+/// let (matched, captures) = router.resolve("/static/css/app.css").unwrap();
+/// assert_eq!(matched.value, "/static/{**path}");
+/// assert_eq!(captures.get("path"), Some(&"css/app.css"));
+/// ```
+#[derive(Debug, Default)]
+pub struct UriRouter<'p> {
+    root: Node<'p>,
+}
+
+/// A single node of the [`UriRouter`] trie.
+#[derive(Debug, Default)]
+struct Node<'p> {
+    literal: HashMap<&'p str, Node<'p>>,
+    joker: Option<Box<Node<'p>>>,
+    multi_joker: Option<Box<Node<'p>>>,
+    /// Patterns terminating at this node. More than one may collide here (e.g.
+    /// `{id}` and `{id:int}`); they are disambiguated by specificity on lookup.
+    patterns: Vec<&'p UriPattern<'p>>,
+}
+
+impl<'p> UriRouter<'p> {
+    /// Creates an empty router.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ingests a pattern into the trie, following one edge per part.
+    pub fn insert(&mut self, pattern: &'p UriPattern<'p>) {
+        let mut node = &mut self.root;
+        for part in &pattern.parts {
+            node = match part {
+                PatternPart::Value(segment) => node.literal.entry(segment).or_default(),
+                PatternPart::Joker(_, _) => node.joker.get_or_insert_with(Box::default),
+                PatternPart::MultiJoker(_) => node.multi_joker.get_or_insert_with(Box::default),
+            };
+        }
+        node.patterns.push(pattern);
+    }
+
+    /// Resolves a candidate string, returning the most specific matching pattern
+    /// together with its captured placeholder map, or `None` if nothing matches.
+    pub fn resolve<'c>(
+        &'c self,
+        candidate: &'c str,
+    ) -> Option<(&'c UriPattern<'c>, HashMap<&'c str, &'c str>)> {
+        let segments = candidate.split('/').collect::<Vec<_>>();
+        let mut candidates = Vec::new();
+        collect(&self.root, &segments, 0, candidate, &mut candidates);
+        let matched = candidates.into_iter().max()?;
+        let captures = matched.captures(candidate)?;
+        Some((matched, captures))
+    }
+}
+
+/// Descends the trie segment by segment, collecting every pattern that matches
+/// `candidate`. The trie only prunes branches that cannot match; the final
+/// choice between the gathered candidates is left to [`UriPattern`] `Ord` so it
+/// matches the `filter(is_match).max()` workflow exactly.
+fn collect<'c>(
+    node: &'c Node<'c>,
+    segments: &[&'c str],
+    index: usize,
+    candidate: &'c str,
+    candidates: &mut Vec<&'c UriPattern<'c>>,
+) {
+    if index == segments.len() {
+        candidates.extend(
+            node.patterns
+                .iter()
+                .copied()
+                .filter(|pattern| pattern.captures(candidate).is_some()),
+        );
+        return;
+    }
+    if let Some(child) = node.literal.get(segments[index]) {
+        collect(child, segments, index + 1, candidate, candidates);
+    }
+    if let Some(child) = &node.joker {
+        collect(child, segments, index + 1, candidate, candidates);
+    }
+    if let Some(child) = &node.multi_joker {
+        // A multi-joker greedily consumes one or more segments; every split is
+        // explored and the pattern's own `captures` check keeps only valid ones.
+        for consumed in 1..=segments.len() - index {
+            collect(child, segments, index + consumed, candidate, candidates);
+        }
+    }
+}
+
+impl<'p> FromIterator<&'p UriPattern<'p>> for UriRouter<'p> {
+    fn from_iter<T: IntoIterator<Item = &'p UriPattern<'p>>>(iter: T) -> Self {
+        let mut router = Self::new();
+        for pattern in iter {
+            router.insert(pattern);
+        }
+        router
+    }
+}